@@ -1,4 +1,6 @@
 use num_traits::real::Real;
+use num_traits::{Num, NumCast, One, ToPrimitive, Zero};
+use std::cmp::Ordering;
 use std::ops;
 
 #[derive(Debug, Clone, PartialEq, Copy)]
@@ -8,6 +10,14 @@ pub struct Dual<R: Real> {
 }
 
 impl<R: Real> Dual<R> {
+    /// A constant: zero gradient with respect to every variable.
+    pub fn constant(real: R) -> Self {
+        Dual { real, grad: R::zero() }
+    }
+    /// The variable being differentiated with respect to: gradient seeded to one.
+    pub fn variable(real: R) -> Self {
+        Dual { real, grad: R::one() }
+    }
     pub fn real(self) -> R {
         self.real
     }
@@ -18,15 +28,53 @@ impl<R: Real> Dual<R> {
         Dual { real: self.real.abs(), grad: self.grad.abs() * self.real.signum() }
     }
     pub fn pow(self, n: usize) -> Self {
-        if n == 1 {
-            self
-        } else {
-            let mut result = self.clone();
-            for _ in 1..n {
-                result *= result.clone();
-            };
-            result
+        self.powi(n as i32)
+    }
+    pub fn sqrt(self) -> Self {
+        let real = self.real.sqrt();
+        Dual { real, grad: self.grad / (R::from(2).unwrap() * real) }
+    }
+    pub fn cbrt(self) -> Self {
+        let real = self.real.cbrt();
+        Dual { real, grad: self.grad / (R::from(3).unwrap() * real * real) }
+    }
+    pub fn powf(self, n: R) -> Self {
+        if n.is_zero() {
+            return Dual::constant(R::one());
         }
+        Dual { real: self.real.powf(n), grad: self.grad * n * self.real.powf(n - R::one()) }
+    }
+    pub fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return Dual::constant(R::one());
+        }
+        Dual { real: self.real.powi(n), grad: self.grad * R::from(n).unwrap() * self.real.powi(n - 1) }
+    }
+    pub fn exp2(self) -> Self {
+        let real = self.real.exp2();
+        Dual { real, grad: self.grad * real * R::from(2).unwrap().ln() }
+    }
+    pub fn log(self, base: R) -> Self {
+        Dual { real: self.real.log(base), grad: self.grad / (self.real * base.ln()) }
+    }
+    pub fn log2(self) -> Self {
+        Dual { real: self.real.log2(), grad: self.grad / (self.real * R::from(2).unwrap().ln()) }
+    }
+    pub fn log10(self) -> Self {
+        Dual { real: self.real.log10(), grad: self.grad / (self.real * R::from(10).unwrap().ln()) }
+    }
+    pub fn hypot(self, other: Self) -> Self {
+        let real = self.real.hypot(other.real);
+        Dual { real, grad: (self.real * self.grad + other.real * other.grad) / real }
+    }
+    pub fn mul_add(self, x: Self, y: Self) -> Self {
+        self * x + y
+    }
+    /// `atan2(y, x)` with `self` as `y` and `other` as `x`; zero gradient at the origin.
+    pub fn atan2(self, other: Self) -> Self {
+        let denom = other.real * other.real + self.real * self.real;
+        let grad = if denom.is_zero() { R::zero() } else { (other.real * self.grad - self.real * other.grad) / denom };
+        Dual { real: self.real.atan2(other.real), grad }
     }
     pub fn exp(self) -> Self {
         Dual { real: self.real.exp(), grad: self.grad * self.real.exp() }
@@ -65,99 +113,838 @@ impl<R: Real> Dual<R> {
         Dual { real: self.real.asinh(), grad: self.grad / (self.real.signum().abs() + self.real * self.real).sqrt() }
     }
     pub fn acosh(self) -> Self {
-        Dual { real: self.real.acos(), grad: self.grad / (-self.real.signum().abs() + self.real * self.real).sqrt() }
+        Dual { real: self.real.acosh(), grad: self.grad / (-self.real.signum().abs() + self.real * self.real).sqrt() }
     }
     pub fn atanh(self) -> Self {
         Dual { real: self.real.atanh(), grad: self.grad / (self.real.signum().abs() - self.real * self.real) }
     }
 }
 
-impl<R> ops::Add for Dual<R>
+/// Expands one invocation into the value, `*Assign`, and `&Dual`-reference
+/// impls for a binary operator at once, so each derivative rule has a single
+/// source of truth instead of four hand-written copies.
+macro_rules! dual_binop {
+    ($trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident, |$a:ident, $b:ident| $body:expr) => {
+        impl<R: Real> ops::$trait for Dual<R> {
+            type Output = Dual<R>;
+            fn $method(self, other: Dual<R>) -> Dual<R> {
+                let $a = self;
+                let $b = other;
+                $body
+            }
+        }
+
+        impl<R: Real> ops::$assign_trait for Dual<R> {
+            fn $assign_method(&mut self, other: Dual<R>) {
+                *self = ops::$trait::$method(*self, other);
+            }
+        }
+
+        impl<'a, R: Real> ops::$trait<&'a Dual<R>> for Dual<R> {
+            type Output = Dual<R>;
+            fn $method(self, other: &'a Dual<R>) -> Dual<R> {
+                ops::$trait::$method(self, *other)
+            }
+        }
+
+        impl<'a, R: Real> ops::$trait<Dual<R>> for &'a Dual<R> {
+            type Output = Dual<R>;
+            fn $method(self, other: Dual<R>) -> Dual<R> {
+                ops::$trait::$method(*self, other)
+            }
+        }
+
+        impl<'a, 'b, R: Real> ops::$trait<&'b Dual<R>> for &'a Dual<R> {
+            type Output = Dual<R>;
+            fn $method(self, other: &'b Dual<R>) -> Dual<R> {
+                ops::$trait::$method(*self, *other)
+            }
+        }
+    };
+}
+
+dual_binop!(Add, add, AddAssign, add_assign, |a, b| Dual {
+    real: a.real + b.real,
+    grad: a.grad + b.grad
+});
+dual_binop!(Sub, sub, SubAssign, sub_assign, |a, b| Dual {
+    real: a.real - b.real,
+    grad: a.grad - b.grad
+});
+dual_binop!(Mul, mul, MulAssign, mul_assign, |a, b| Dual {
+    real: a.real * b.real,
+    grad: a.real * b.grad + a.grad * b.real
+});
+dual_binop!(Div, div, DivAssign, div_assign, |a, b| Dual {
+    real: a.real / b.real,
+    grad: (a.grad * b.real - a.real * b.grad) / (b.real * b.real)
+});
+
+impl<R: Real> ops::Neg for Dual<R> {
+    type Output = Dual<R>;
+    fn neg(self) -> Dual<R> {
+        Dual { real: -self.real, grad: -self.grad }
+    }
+}
+
+impl<R: Real> ops::Neg for &Dual<R> {
+    type Output = Dual<R>;
+    fn neg(self) -> Dual<R> {
+        -(*self)
+    }
+}
+
+impl<R> ops::Add<R> for Dual<R>
 where
     R: Real,
 {
     type Output = Dual<R>;
-    fn add(self, other: Self) -> Dual<R> {
-        Dual { real: self.real + other.real, grad: self.grad + other.grad }
+    fn add(self, other: R) -> Dual<R> {
+        Dual { real: self.real + other, grad: self.grad }
     }
 }
 
-impl <R> ops::AddAssign for Dual<R>
+impl<R> ops::AddAssign<R> for Dual<R>
 where
-    R :Real {
-    fn add_assign(&mut self, other: Self) {
-        *self = Self { real: self.real + other.real, grad: self.grad + other.grad };
+    R: Real,
+{
+    fn add_assign(&mut self, other: R) {
+        *self = Self { real: self.real + other, grad: self.grad };
+    }
+}
+
+impl ops::Add<Dual<f64>> for f64 {
+    type Output = Dual<f64>;
+    fn add(self, other: Dual<f64>) -> Dual<f64> {
+        Dual { real: self + other.real, grad: other.grad }
+    }
+}
+
+impl ops::Add<Dual<f32>> for f32 {
+    type Output = Dual<f32>;
+    fn add(self, other: Dual<f32>) -> Dual<f32> {
+        Dual { real: self + other.real, grad: other.grad }
     }
 }
 
-impl<R> ops::Div for Dual<R>
+impl<R> ops::Sub<R> for Dual<R>
 where
     R: Real,
 {
     type Output = Dual<R>;
-    fn div(self, other: Self) -> Dual<R> {
-        Dual { real: self.real / other.real, grad: self.real / other.grad + self.grad / other.real }
+    fn sub(self, other: R) -> Dual<R> {
+        Dual { real: self.real - other, grad: self.grad }
     }
 }
 
-impl <R> ops::DivAssign for Dual<R>
+impl<R> ops::SubAssign<R> for Dual<R>
 where
-    R :Real {
-    fn div_assign(&mut self, other: Self) {
-        *self = Self { real: self.real / other.real, grad: self.real / other.grad + self.grad / other.real }
+    R: Real,
+{
+    fn sub_assign(&mut self, other: R) {
+        *self = Self { real: self.real - other, grad: self.grad };
+    }
+}
+
+impl ops::Sub<Dual<f64>> for f64 {
+    type Output = Dual<f64>;
+    fn sub(self, other: Dual<f64>) -> Dual<f64> {
+        Dual { real: self - other.real, grad: -other.grad }
+    }
+}
+
+impl ops::Sub<Dual<f32>> for f32 {
+    type Output = Dual<f32>;
+    fn sub(self, other: Dual<f32>) -> Dual<f32> {
+        Dual { real: self - other.real, grad: -other.grad }
     }
 }
 
-impl<R> ops::Mul for Dual<R>
+impl<R> ops::Mul<R> for Dual<R>
 where
     R: Real,
 {
     type Output = Dual<R>;
-    fn mul(self, other: Self) -> Dual<R> {
-        Dual { real: self.real * other.real, grad: self.real * other.grad + self.grad * other.real }
+    fn mul(self, other: R) -> Dual<R> {
+        Dual { real: self.real * other, grad: self.grad * other }
     }
 }
 
-impl <R> ops::MulAssign for Dual<R>
+impl<R> ops::MulAssign<R> for Dual<R>
 where
-    R :Real {
-    fn mul_assign(&mut self, other: Self) {
-        *self = Self { real: self.real * other.real, grad: self.real * other.grad + self.grad * other.real }
+    R: Real,
+{
+    fn mul_assign(&mut self, other: R) {
+        *self = Self { real: self.real * other, grad: self.grad * other };
+    }
+}
+
+impl ops::Mul<Dual<f64>> for f64 {
+    type Output = Dual<f64>;
+    fn mul(self, other: Dual<f64>) -> Dual<f64> {
+        Dual { real: self * other.real, grad: self * other.grad }
     }
 }
 
-impl<R> ops::Neg for Dual<R>
+impl ops::Mul<Dual<f32>> for f32 {
+    type Output = Dual<f32>;
+    fn mul(self, other: Dual<f32>) -> Dual<f32> {
+        Dual { real: self * other.real, grad: self * other.grad }
+    }
+}
+
+impl<R> ops::Div<R> for Dual<R>
 where
     R: Real,
 {
     type Output = Dual<R>;
-    fn neg(self) -> Dual<R> {
-        Dual { real: -self.grad, grad: -self.real }
+    fn div(self, other: R) -> Dual<R> {
+        Dual { real: self.real / other, grad: self.grad / other }
     }
 }
 
-impl<R> ops::Sub for Dual<R>
+impl<R> ops::DivAssign<R> for Dual<R>
 where
     R: Real,
 {
+    fn div_assign(&mut self, other: R) {
+        *self = Self { real: self.real / other, grad: self.grad / other };
+    }
+}
+
+impl ops::Div<Dual<f64>> for f64 {
+    type Output = Dual<f64>;
+    fn div(self, other: Dual<f64>) -> Dual<f64> {
+        Dual { real: self / other.real, grad: -self * other.grad / (other.real * other.real) }
+    }
+}
+
+impl ops::Div<Dual<f32>> for f32 {
+    type Output = Dual<f32>;
+    fn div(self, other: Dual<f32>) -> Dual<f32> {
+        Dual { real: self / other.real, grad: -self * other.grad / (other.real * other.real) }
+    }
+}
+
+/// Seeds `x` as the variable, applies `f`, and returns `df/dx` evaluated at `x`.
+pub fn differentiate<R: Real>(x: R, f: impl Fn(Dual<R>) -> Dual<R>) -> R {
+    f(Dual::variable(x)).grad
+}
+
+impl<R: Real> Zero for Dual<R> {
+    fn zero() -> Self {
+        Dual::constant(R::zero())
+    }
+    fn is_zero(&self) -> bool {
+        self.real.is_zero()
+    }
+}
+
+impl<R: Real> One for Dual<R> {
+    fn one() -> Self {
+        Dual::constant(R::one())
+    }
+}
+
+/// Ordered by the real part alone, matching the usual treatment of dual
+/// numbers as the reals extended with an infinitesimal: only the real part
+/// determines magnitude.
+impl<R: Real> PartialOrd for Dual<R> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.real.partial_cmp(&other.real)
+    }
+}
+
+impl<R: Real> ops::Rem for Dual<R> {
     type Output = Dual<R>;
-    fn sub(self, other: Self) -> Dual<R> {
-        Dual { real: self.real - other.real, grad: self.grad - other.grad }
+    fn rem(self, other: Dual<R>) -> Dual<R> {
+        let real = self.real % other.real;
+        let grad = self.grad - (self.real / other.real).trunc() * other.grad;
+        Dual { real, grad }
     }
 }
 
-impl <R> ops::SubAssign for Dual<R>
-where
-    R :Real {
+impl<R: Real> ToPrimitive for Dual<R> {
+    fn to_i64(&self) -> Option<i64> {
+        self.real.to_i64()
+    }
+    fn to_u64(&self) -> Option<u64> {
+        self.real.to_u64()
+    }
+    fn to_f64(&self) -> Option<f64> {
+        self.real.to_f64()
+    }
+}
+
+impl<R: Real> NumCast for Dual<R> {
+    fn from<T: ToPrimitive>(n: T) -> Option<Self> {
+        R::from(n).map(Dual::constant)
+    }
+}
+
+impl<R: Real> Num for Dual<R> {
+    type FromStrRadixErr = R::FromStrRadixErr;
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        R::from_str_radix(str, radix).map(Dual::constant)
+    }
+}
+
+// `Real` and `Float` both define near-identical method names (`floor`, `sin`,
+// `min_value`, ...), so bringing `Float` into scope anywhere `Real` already is
+// makes every such call ambiguous. Isolate the `Float` impl in its own module
+// so the `Real` imported at the top of this file, and glob-imported by its
+// tests, stays unambiguous.
+mod float_impl {
+    use super::*;
+    use num_traits::Float;
+
+    impl<R: Real + Float> Float for Dual<R> {
+        fn nan() -> Self {
+            Dual::constant(R::nan())
+        }
+        fn infinity() -> Self {
+            Dual::constant(R::infinity())
+        }
+        fn neg_infinity() -> Self {
+            Dual::constant(R::neg_infinity())
+        }
+        fn neg_zero() -> Self {
+            Dual::constant(R::neg_zero())
+        }
+        fn min_value() -> Self {
+            Dual::constant(Real::min_value())
+        }
+        fn min_positive_value() -> Self {
+            Dual::constant(Real::min_positive_value())
+        }
+        fn epsilon() -> Self {
+            Dual::constant(Real::epsilon())
+        }
+        fn max_value() -> Self {
+            Dual::constant(Real::max_value())
+        }
+        fn is_nan(self) -> bool {
+            self.real.is_nan() || self.grad.is_nan()
+        }
+        fn is_infinite(self) -> bool {
+            self.real.is_infinite()
+        }
+        fn is_finite(self) -> bool {
+            self.real.is_finite()
+        }
+        fn is_normal(self) -> bool {
+            self.real.is_normal()
+        }
+        fn classify(self) -> std::num::FpCategory {
+            self.real.classify()
+        }
+        fn floor(self) -> Self {
+            Dual { real: Real::floor(self.real), grad: R::zero() }
+        }
+        fn ceil(self) -> Self {
+            Dual { real: Real::ceil(self.real), grad: R::zero() }
+        }
+        fn round(self) -> Self {
+            Dual { real: Real::round(self.real), grad: R::zero() }
+        }
+        fn trunc(self) -> Self {
+            Dual { real: Real::trunc(self.real), grad: R::zero() }
+        }
+        fn fract(self) -> Self {
+            Dual { real: Real::fract(self.real), grad: self.grad }
+        }
+        fn abs(self) -> Self {
+            Dual::abs(self)
+        }
+        fn signum(self) -> Self {
+            Dual::constant(Real::signum(self.real))
+        }
+        fn is_sign_positive(self) -> bool {
+            Real::is_sign_positive(self.real)
+        }
+        fn is_sign_negative(self) -> bool {
+            Real::is_sign_negative(self.real)
+        }
+        fn mul_add(self, a: Self, b: Self) -> Self {
+            Dual::mul_add(self, a, b)
+        }
+        fn recip(self) -> Self {
+            Dual { real: Real::recip(self.real), grad: -self.grad / (self.real * self.real) }
+        }
+        fn powi(self, n: i32) -> Self {
+            Dual::powi(self, n)
+        }
+        fn powf(self, n: Self) -> Self {
+            if n.grad.is_zero() {
+                // Constant exponent: fall back to the plain power rule, which stays
+                // finite at `self.real == 0.0` instead of dividing by it.
+                return Dual::powf(self, n.real);
+            }
+            let real = Real::powf(self.real, n.real);
+            Dual { real, grad: real * (n.grad * Real::ln(self.real) + n.real * self.grad / self.real) }
+        }
+        fn sqrt(self) -> Self {
+            Dual::sqrt(self)
+        }
+        fn exp(self) -> Self {
+            Dual::exp(self)
+        }
+        fn exp2(self) -> Self {
+            Dual::exp2(self)
+        }
+        fn ln(self) -> Self {
+            Dual::ln(self)
+        }
+        fn log(self, base: Self) -> Self {
+            let ln_base = Real::ln(base.real);
+            Dual {
+                real: Real::log(self.real, base.real),
+                grad: self.grad / (self.real * ln_base) - Real::ln(self.real) * base.grad / (base.real * ln_base * ln_base),
+            }
+        }
+        fn log2(self) -> Self {
+            Dual::log2(self)
+        }
+        fn log10(self) -> Self {
+            Dual::log10(self)
+        }
+        fn to_degrees(self) -> Self {
+            Dual { real: Real::to_degrees(self.real), grad: Real::to_degrees(self.grad) }
+        }
+        fn to_radians(self) -> Self {
+            Dual { real: Real::to_radians(self.real), grad: Real::to_radians(self.grad) }
+        }
+        fn max(self, other: Self) -> Self {
+            if self.real >= other.real {
+                self
+            } else {
+                other
+            }
+        }
+        fn min(self, other: Self) -> Self {
+            if self.real <= other.real {
+                self
+            } else {
+                other
+            }
+        }
+        fn abs_sub(self, other: Self) -> Self {
+            if self.real > other.real {
+                self - other
+            } else {
+                Dual::zero()
+            }
+        }
+        fn cbrt(self) -> Self {
+            Dual::cbrt(self)
+        }
+        fn hypot(self, other: Self) -> Self {
+            Dual::hypot(self, other)
+        }
+        fn sin(self) -> Self {
+            Dual::sin(self)
+        }
+        fn cos(self) -> Self {
+            Dual::cos(self)
+        }
+        fn tan(self) -> Self {
+            Dual::tan(self)
+        }
+        fn asin(self) -> Self {
+            Dual::asin(self)
+        }
+        fn acos(self) -> Self {
+            Dual::acos(self)
+        }
+        fn atan(self) -> Self {
+            Dual::atan(self)
+        }
+        fn atan2(self, other: Self) -> Self {
+            Dual::atan2(self, other)
+        }
+        fn sin_cos(self) -> (Self, Self) {
+            (self.sin(), self.cos())
+        }
+        fn exp_m1(self) -> Self {
+            Dual { real: Real::exp_m1(self.real), grad: self.grad * Real::exp(self.real) }
+        }
+        fn ln_1p(self) -> Self {
+            Dual { real: Real::ln_1p(self.real), grad: self.grad / (R::one() + self.real) }
+        }
+        fn sinh(self) -> Self {
+            Dual::sinh(self)
+        }
+        fn cosh(self) -> Self {
+            Dual::cosh(self)
+        }
+        fn tanh(self) -> Self {
+            Dual::tanh(self)
+        }
+        fn asinh(self) -> Self {
+            Dual::asinh(self)
+        }
+        fn acosh(self) -> Self {
+            Dual::acosh(self)
+        }
+        fn atanh(self) -> Self {
+            Dual::atanh(self)
+        }
+        fn integer_decode(self) -> (u64, i16, i8) {
+            self.real.integer_decode()
+        }
+    }
+}
+
+/// Forward-mode dual number carrying a full `N`-length gradient, so a single
+/// evaluation yields every partial derivative of an `N`-input function.
+#[derive(Debug, Clone, PartialEq, Copy)]
+pub struct MultiDual<R: Real, const N: usize> {
+    pub real: R,
+    pub grad: [R; N],
+}
+
+impl<R: Real, const N: usize> MultiDual<R, N> {
+    /// A constant: zero gradient with respect to every input.
+    pub fn constant(real: R) -> Self {
+        MultiDual { real, grad: [R::zero(); N] }
+    }
+    /// The `index`-th input: gradient seeded to the `index`-th basis vector.
+    pub fn variable(real: R, index: usize) -> Self {
+        let mut grad = [R::zero(); N];
+        grad[index] = R::one();
+        MultiDual { real, grad }
+    }
+    pub fn real(self) -> R {
+        self.real
+    }
+    pub fn grad(self) -> [R; N] {
+        self.grad
+    }
+    pub fn abs(self) -> Self {
+        let sign = self.real.signum();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * sign;
+        }
+        MultiDual { real: self.real.abs(), grad }
+    }
+    pub fn sqrt(self) -> Self {
+        let real = self.real.sqrt();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / (R::from(2).unwrap() * real);
+        }
+        MultiDual { real, grad }
+    }
+    pub fn pow(self, n: usize) -> Self {
+        self.powi(n as i32)
+    }
+    pub fn powi(self, n: i32) -> Self {
+        if n == 0 {
+            return MultiDual::constant(R::one());
+        }
+        let factor = R::from(n).unwrap() * self.real.powi(n - 1);
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * factor;
+        }
+        MultiDual { real: self.real.powi(n), grad }
+    }
+    pub fn powf(self, n: R) -> Self {
+        if n.is_zero() {
+            return MultiDual::constant(R::one());
+        }
+        let factor = n * self.real.powf(n - R::one());
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * factor;
+        }
+        MultiDual { real: self.real.powf(n), grad }
+    }
+    pub fn cbrt(self) -> Self {
+        let real = self.real.cbrt();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / (R::from(3).unwrap() * real * real);
+        }
+        MultiDual { real, grad }
+    }
+    pub fn exp2(self) -> Self {
+        let real = self.real.exp2();
+        let factor = real * R::from(2).unwrap().ln();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * factor;
+        }
+        MultiDual { real, grad }
+    }
+    pub fn log(self, base: R) -> Self {
+        let factor = self.real * base.ln();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / factor;
+        }
+        MultiDual { real: self.real.log(base), grad }
+    }
+    pub fn log2(self) -> Self {
+        let factor = self.real * R::from(2).unwrap().ln();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / factor;
+        }
+        MultiDual { real: self.real.log2(), grad }
+    }
+    pub fn log10(self) -> Self {
+        let factor = self.real * R::from(10).unwrap().ln();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / factor;
+        }
+        MultiDual { real: self.real.log10(), grad }
+    }
+    pub fn hypot(self, other: Self) -> Self {
+        let real = self.real.hypot(other.real);
+        let mut grad = [R::zero(); N];
+        for ((g, sg), og) in grad.iter_mut().zip(self.grad).zip(other.grad) {
+            *g = (self.real * sg + other.real * og) / real;
+        }
+        MultiDual { real, grad }
+    }
+    pub fn mul_add(self, x: Self, y: Self) -> Self {
+        self * x + y
+    }
+    /// `atan2(y, x)` with `self` as `y` and `other` as `x`; zero gradient at the origin.
+    pub fn atan2(self, other: Self) -> Self {
+        let denom = other.real * other.real + self.real * self.real;
+        let mut grad = [R::zero(); N];
+        for ((g, sg), og) in grad.iter_mut().zip(self.grad).zip(other.grad) {
+            *g = if denom.is_zero() { R::zero() } else { (other.real * sg - self.real * og) / denom };
+        }
+        MultiDual { real: self.real.atan2(other.real), grad }
+    }
+    pub fn exp(self) -> Self {
+        let real = self.real.exp();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * real;
+        }
+        MultiDual { real, grad }
+    }
+    pub fn ln(self) -> Self {
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / self.real;
+        }
+        MultiDual { real: self.real.ln(), grad }
+    }
+    pub fn sin(self) -> Self {
+        let cos = self.real.cos();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * cos;
+        }
+        MultiDual { real: self.real.sin(), grad }
+    }
+    pub fn cos(self) -> Self {
+        let sin = self.real.sin();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = -(*g) * sin;
+        }
+        MultiDual { real: self.real.cos(), grad }
+    }
+    pub fn tan(self) -> Self {
+        let cos = self.real.cos();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / (cos * cos);
+        }
+        MultiDual { real: self.real.tan(), grad }
+    }
+    pub fn asin(self) -> Self {
+        let factor = (self.real.signum().abs() - self.real * self.real).sqrt();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / factor;
+        }
+        MultiDual { real: self.real.asin(), grad }
+    }
+    pub fn acos(self) -> Self {
+        let factor = (self.real.signum().abs() - self.real * self.real).sqrt();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = -(*g) / factor;
+        }
+        MultiDual { real: self.real.acos(), grad }
+    }
+    pub fn atan(self) -> Self {
+        let factor = self.real.signum().abs() + self.real * self.real;
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / factor;
+        }
+        MultiDual { real: self.real.atan(), grad }
+    }
+    pub fn sinh(self) -> Self {
+        let cosh = self.real.cosh();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * cosh;
+        }
+        MultiDual { real: self.real.sinh(), grad }
+    }
+    pub fn cosh(self) -> Self {
+        let sinh = self.real.sinh();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * sinh;
+        }
+        MultiDual { real: self.real.cosh(), grad }
+    }
+    pub fn tanh(self) -> Self {
+        let factor = (self.real.exp() - (-self.real).exp()) / (self.real.exp() + (-self.real).exp());
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g * factor;
+        }
+        MultiDual { real: self.real.tanh(), grad }
+    }
+    pub fn asinh(self) -> Self {
+        let factor = (self.real.signum().abs() + self.real * self.real).sqrt();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / factor;
+        }
+        MultiDual { real: self.real.asinh(), grad }
+    }
+    pub fn acosh(self) -> Self {
+        let factor = (-self.real.signum().abs() + self.real * self.real).sqrt();
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / factor;
+        }
+        MultiDual { real: self.real.acosh(), grad }
+    }
+    pub fn atanh(self) -> Self {
+        let factor = self.real.signum().abs() - self.real * self.real;
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = *g / factor;
+        }
+        MultiDual { real: self.real.atanh(), grad }
+    }
+}
+
+impl<R: Real, const N: usize> ops::Add for MultiDual<R, N> {
+    type Output = Self;
+    fn add(self, other: Self) -> Self {
+        let mut grad = self.grad;
+        for (g, og) in grad.iter_mut().zip(other.grad) {
+            *g = *g + og;
+        }
+        MultiDual { real: self.real + other.real, grad }
+    }
+}
+
+impl<R: Real, const N: usize> ops::AddAssign for MultiDual<R, N> {
+    fn add_assign(&mut self, other: Self) {
+        *self = *self + other;
+    }
+}
+
+impl<R: Real, const N: usize> ops::Sub for MultiDual<R, N> {
+    type Output = Self;
+    fn sub(self, other: Self) -> Self {
+        let mut grad = self.grad;
+        for (g, og) in grad.iter_mut().zip(other.grad) {
+            *g = *g - og;
+        }
+        MultiDual { real: self.real - other.real, grad }
+    }
+}
+
+impl<R: Real, const N: usize> ops::SubAssign for MultiDual<R, N> {
     fn sub_assign(&mut self, other: Self) {
-        *self = Self { real: self.real - other.real, grad: self.grad - other.grad };
+        *self = *self - other;
+    }
+}
+
+impl<R: Real, const N: usize> ops::Neg for MultiDual<R, N> {
+    type Output = Self;
+    fn neg(self) -> Self {
+        let mut grad = self.grad;
+        for g in grad.iter_mut() {
+            *g = -(*g);
+        }
+        MultiDual { real: -self.real, grad }
     }
 }
 
+impl<R: Real, const N: usize> ops::Mul for MultiDual<R, N> {
+    type Output = Self;
+    fn mul(self, other: Self) -> Self {
+        let mut grad = [R::zero(); N];
+        for ((g, sg), og) in grad.iter_mut().zip(self.grad).zip(other.grad) {
+            *g = self.real * og + sg * other.real;
+        }
+        MultiDual { real: self.real * other.real, grad }
+    }
+}
+
+impl<R: Real, const N: usize> ops::MulAssign for MultiDual<R, N> {
+    fn mul_assign(&mut self, other: Self) {
+        *self = *self * other;
+    }
+}
+
+impl<R: Real, const N: usize> ops::Div for MultiDual<R, N> {
+    type Output = Self;
+    fn div(self, other: Self) -> Self {
+        let mut grad = [R::zero(); N];
+        for ((g, sg), og) in grad.iter_mut().zip(self.grad).zip(other.grad) {
+            *g = (sg * other.real - self.real * og) / (other.real * other.real);
+        }
+        MultiDual { real: self.real / other.real, grad }
+    }
+}
+
+impl<R: Real, const N: usize> ops::DivAssign for MultiDual<R, N> {
+    fn div_assign(&mut self, other: Self) {
+        *self = *self / other;
+    }
+}
+
+/// Seeds each input with its basis vector, applies `f`, and returns the full
+/// gradient `df/dx` in one evaluation.
+pub fn gradient<R: Real, const N: usize>(x: [R; N], f: impl Fn([MultiDual<R, N>; N]) -> MultiDual<R, N>) -> [R; N] {
+    let inputs = std::array::from_fn(|i| MultiDual::variable(x[i], i));
+    f(inputs).grad
+}
+
 #[cfg(test)]
 mod tests {
     use crate::forward::*;
 
+    #[test]
+    fn test_scalar_rhs_ops() {
+        let x = Dual { real: 2.0, grad: 1.0 };
+        assert_eq!((x + 3.0).real, 5.0);
+        assert_eq!((x + 3.0).grad, 1.0);
+        assert_eq!((x * 2.0).real, 4.0);
+        assert_eq!((x * 2.0).grad, 2.0);
+        assert_eq!((x / 2.0).real, 1.0);
+        assert_eq!((x / 2.0).grad, 0.5);
+    }
+
+    #[test]
+    fn test_scalar_lhs_ops() {
+        let x: Dual<f64> = Dual { real: 2.0, grad: 1.0 };
+        assert_eq!((2.0 - x).real, 0.0);
+        assert_eq!((2.0 - x).grad, -1.0);
+        assert_eq!((2.0 * x).real, 4.0);
+        assert_eq!((2.0 * x).grad, 2.0);
+    }
+
     #[test]
     fn test_grad_0_1() {
         let f = |x: Dual<f64>| x*x + x.sin();
@@ -165,9 +952,177 @@ mod tests {
         assert!(f(Dual{ real: 0.0, grad: 1.0 }).grad > 0.9);
     }
     #[test]
+    #[allow(clippy::approx_constant)]
     fn test_grad_pi_1() {
         let f = |x: Dual<f64>| x*x + x.sin();
         assert!(f(Dual{ real: 3.14, grad: 1.0 }).grad < 5.3);
         assert!(f(Dual{ real: 3.14, grad: 1.0 }).grad > 5.2);
     }
+
+    #[test]
+    fn test_constant_has_zero_grad() {
+        let c = Dual::constant(4.0);
+        assert_eq!(c.real, 4.0);
+        assert_eq!(c.grad, 0.0);
+    }
+
+    #[test]
+    fn test_variable_has_unit_grad() {
+        let v = Dual::variable(4.0);
+        assert_eq!(v.real, 4.0);
+        assert_eq!(v.grad, 1.0);
+    }
+
+    #[test]
+    fn test_differentiate() {
+        let d = differentiate(2.0, |x: Dual<f64>| x * x + x);
+        assert!((d - 5.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_sqrt() {
+        let x = Dual::variable(4.0);
+        let y = x.sqrt();
+        assert_eq!(y.real, 2.0);
+        assert!((y.grad - 0.25).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_powf_and_powi() {
+        let x = Dual::variable(2.0);
+        let y = x.powf(3.0);
+        assert_eq!(y.real, 8.0);
+        assert!((y.grad - 12.0).abs() < 1e-10);
+        assert_eq!(x.powi(3).real, 8.0);
+        assert!((x.powi(3).grad - 12.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_powi_and_powf_zero_exponent_at_zero_base() {
+        let x = Dual::variable(0.0);
+        assert_eq!(x.powi(0).real, 1.0);
+        assert_eq!(x.powi(0).grad, 0.0);
+        assert_eq!(x.powf(0.0).real, 1.0);
+        assert_eq!(x.powf(0.0).grad, 0.0);
+    }
+
+    #[test]
+    fn test_float_powf_constant_exponent_at_zero_base() {
+        use num_traits::Float;
+        let x = Dual::variable(0.0);
+        let n = Dual::constant(2.0);
+        let y = Float::powf(x, n);
+        assert_eq!(y.real, 0.0);
+        assert_eq!(y.grad, 0.0);
+    }
+
+    #[test]
+    fn test_pow_zero_is_one() {
+        let x = Dual::variable(5.0);
+        assert_eq!(x.pow(0).real, 1.0);
+        assert_eq!(x.pow(0).grad, 0.0);
+    }
+
+    #[test]
+    fn test_pow_matches_repeated_multiplication() {
+        let x = Dual::variable(2.0);
+        assert_eq!(x.pow(3).real, 8.0);
+        assert_eq!(x.pow(4).real, 16.0);
+        assert_eq!(x.pow(5).real, 32.0);
+    }
+
+    #[test]
+    fn test_atan2() {
+        let y = Dual::variable(1.0);
+        let x = Dual::constant(1.0);
+        let a = y.atan2(x);
+        assert!((a.real - std::f64::consts::FRAC_PI_4).abs() < 1e-10);
+        assert!((a.grad - 0.5).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_atan2_at_origin_has_zero_grad() {
+        let y = Dual::variable(0.0);
+        let x = Dual::constant(0.0);
+        assert_eq!(y.atan2(x).grad, 0.0);
+    }
+
+    #[test]
+    fn test_hypot_and_mul_add() {
+        let x = Dual::constant(3.0);
+        let y = Dual::variable(4.0);
+        assert_eq!(x.hypot(y).real, 5.0);
+        assert_eq!(x.mul_add(y, Dual::constant(1.0)).real, 13.0);
+    }
+
+    #[test]
+    fn test_acosh() {
+        let x = Dual::variable(2.0);
+        let y = x.acosh();
+        assert!((y.real - 1.3169578969248166).abs() < 1e-10);
+        assert!((y.grad - 1.0 / 3.0_f64.sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_multi_dual_mul() {
+        let x = MultiDual::<f64, 2>::variable(2.0, 0);
+        let y = MultiDual::<f64, 2>::variable(3.0, 1);
+        let z = x * y;
+        assert_eq!(z.real, 6.0);
+        assert_eq!(z.grad, [3.0, 2.0]);
+    }
+
+    #[test]
+    fn test_gradient() {
+        let g = gradient([2.0, 3.0], |x: [MultiDual<f64, 2>; 2]| x[0] * x[1] + x[0].sin());
+        assert!((g[0] - (3.0 + 2.0_f64.cos())).abs() < 1e-10);
+        assert!((g[1] - 2.0).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_multi_dual_hypot_and_atan2_cartesian_to_polar() {
+        let g = gradient([3.0, 4.0], |x: [MultiDual<f64, 2>; 2]| x[0].hypot(x[1]));
+        assert!((g[0] - 0.6).abs() < 1e-10);
+        assert!((g[1] - 0.8).abs() < 1e-10);
+
+        let g = gradient([3.0, 4.0], |x: [MultiDual<f64, 2>; 2]| x[1].atan2(x[0]));
+        let r2 = 3.0_f64 * 3.0 + 4.0 * 4.0;
+        assert!((g[0] - (-4.0 / r2)).abs() < 1e-10);
+        assert!((g[1] - (3.0 / r2)).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_multi_dual_acosh() {
+        let x = MultiDual::<f64, 1>::variable(2.0, 0);
+        let y = x.acosh();
+        assert!((y.real - 1.3169578969248166).abs() < 1e-10);
+        assert!((y.grad[0] - 1.0 / 3.0_f64.sqrt()).abs() < 1e-10);
+    }
+
+    #[test]
+    fn test_div_gradient_is_quotient_rule() {
+        let x = Dual { real: 6.0, grad: 1.0 };
+        let y = Dual { real: 2.0, grad: 0.0 };
+        let z = x / y;
+        assert_eq!(z.real, 3.0);
+        assert_eq!(z.grad, 0.5);
+    }
+
+    #[test]
+    fn test_neg_keeps_real_and_grad_paired() {
+        let x = Dual { real: 2.0, grad: 3.0 };
+        let n = -x;
+        assert_eq!(n.real, -2.0);
+        assert_eq!(n.grad, -3.0);
+    }
+
+    #[test]
+    #[allow(clippy::op_ref)]
+    fn test_reference_operands() {
+        let x = Dual { real: 2.0, grad: 1.0 };
+        let y = Dual { real: 3.0, grad: 0.0 };
+        assert_eq!((&x + &y).real, 5.0);
+        assert_eq!((x + &y).real, 5.0);
+        assert_eq!((&x + y).real, 5.0);
+    }
 }